@@ -1,6 +1,6 @@
 import type { CompanionVariableDefinition } from '@companion-module/base'
 import type VMixInstance from '../'
-import type { AudioBus } from '../data'
+import type { AudioBus, AudioBusses } from '../data'
 import { AUDIOBUSSESMASTER, volumeTodB, volumeToLinear } from '../utils'
 
 type VariablesBusIDs =
@@ -14,6 +14,7 @@ type VariablesBusIDs =
   | `bus_${string}_meterf${number}_avg_3s`
   | `bus_${string}_meterf${number}_peak_1s`
   | `bus_${string}_meterf${number}_peak_3s`
+  | `bus_${string}_send_count`
   | `bus_${string}_mute`
   | `bus_${string}_solo`
   | `bus_${string}_sendtomaster`
@@ -35,6 +36,8 @@ export const audioDefinitions = (instance: VMixInstance): CompanionVariableDefin
       )
 
       if (bus !== 'Headphones') {
+        definitions.push({ name: `Bus ${bus} Input Send Count`, variableId: `bus_${bus.toLowerCase()}_send_count` })
+
         for (let i = 1; i < 3; i++) {
           const audioLevelID = bus === 'Master' ? 'master' : `bus${bus}`
           const audioLevel = instance.data.audioLevels.find((level) => level.key === audioLevelID)
@@ -104,6 +107,10 @@ export const audioValues = async (instance: VMixInstance): Promise<VariablesBusV
       variables[`bus_${id.toLowerCase()}_volume_linear`] = volumeLinear
 
       if (id !== 'Headphones') {
+        const sendBusID = id === 'Selected' ? instance.routingData.bus : id
+        const sendBus = (sendBusID === 'Master' ? 'M' : sendBusID) as keyof AudioBusses
+        variables[`bus_${id.toLowerCase()}_send_count`] = instance.data.inputs.filter((input) => input.audioBusses?.[sendBus]).length
+
         const audioLevelID = id === 'Master' ? 'master' : `bus${id}`
         const audioLevel = instance.data.audioLevels.find((level) => level.key === audioLevelID)
         if (audioLevel) {